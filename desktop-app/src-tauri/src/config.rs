@@ -0,0 +1,171 @@
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+
+const CONFIG_FILE_NAME: &str = "models_config.json";
+
+/// Used the first time the app runs, before any root has been registered,
+/// so dev builds keep working without extra setup.
+const DEFAULT_DEV_MODELS_DIR: &str = "../../models";
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+struct PersistedConfig {
+    roots: Vec<PathBuf>,
+}
+
+/// Shared, file-backed registry of model library roots. Every command that
+/// touches the filesystem resolves against this list instead of a literal
+/// path, and `resolve_within_roots` refuses anything outside it.
+pub struct ModelsConfig {
+    config_path: PathBuf,
+    roots: Mutex<Vec<PathBuf>>,
+}
+
+impl ModelsConfig {
+    /// Loads persisted roots from the app's config directory (resolved via
+    /// Tauri's path resolver, not a relative literal, so this still works
+    /// once the app is bundled and the CWD is no longer `src-tauri`).
+    pub fn load(app: &AppHandle) -> Self {
+        let config_dir = app
+            .path_resolver()
+            .app_config_dir()
+            .unwrap_or_else(|| PathBuf::from("."));
+        let _ = fs::create_dir_all(&config_dir);
+        let config_path = config_dir.join(CONFIG_FILE_NAME);
+
+        let persisted = fs::read_to_string(&config_path)
+            .ok()
+            .and_then(|raw| serde_json::from_str::<PersistedConfig>(&raw).ok())
+            .unwrap_or_default();
+
+        let roots = if persisted.roots.is_empty() {
+            vec![PathBuf::from(DEFAULT_DEV_MODELS_DIR)]
+        } else {
+            persisted.roots
+        };
+
+        ModelsConfig {
+            config_path,
+            roots: Mutex::new(roots),
+        }
+    }
+
+    pub fn roots(&self) -> Vec<PathBuf> {
+        self.roots.lock().unwrap().clone()
+    }
+
+    /// The root new imports/downloads land in: the most recently registered
+    /// one, falling back to the dev default if nothing has been registered.
+    pub fn primary_root(&self) -> PathBuf {
+        self.roots
+            .lock()
+            .unwrap()
+            .last()
+            .cloned()
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_DEV_MODELS_DIR))
+    }
+
+    pub fn add_root(&self, dir: PathBuf) -> Result<(), String> {
+        let mut roots = self.roots.lock().unwrap();
+        if !roots.contains(&dir) {
+            roots.push(dir);
+        }
+        self.persist(&roots)
+    }
+
+    /// Returns the resolved form of `path` (see below) if it falls within
+    /// one of the registered roots, otherwise an error. Commands that read or write files under a
+    /// models directory should call this before touching the filesystem so
+    /// they can't be coerced into operating outside the registered
+    /// libraries.
+    ///
+    /// `path` is resolved to an absolute, lexically-normalized form first
+    /// (`..`/`.` components collapsed) before the prefix check, so a
+    /// destination that doesn't exist yet (and can't be `canonicalize`d)
+    /// can't escape the allowlist via `../../../../somewhere/else`.
+    pub fn resolve_within_roots(&self, path: &Path) -> Result<PathBuf, String> {
+        let roots = self.roots.lock().unwrap();
+        let absolute = normalize_lexically(&to_absolute(path)?);
+
+        for root in roots.iter() {
+            let root_absolute = normalize_lexically(&to_absolute(root)?);
+            let within = match root_absolute.canonicalize() {
+                Ok(canon_root) => match absolute.canonicalize() {
+                    Ok(canon_path) => canon_path.starts_with(&canon_root),
+                    // The destination may not exist yet (we're about to
+                    // create it); compare the normalized form against the
+                    // root's real, symlink-resolved location instead.
+                    Err(_) => absolute.starts_with(&canon_root),
+                },
+                Err(_) => absolute.starts_with(&root_absolute),
+            };
+            if within {
+                return Ok(absolute);
+            }
+        }
+
+        Err(format!(
+            "{:?} is outside the registered model library roots",
+            path
+        ))
+    }
+
+    fn persist(&self, roots: &[PathBuf]) -> Result<(), String> {
+        let persisted = PersistedConfig {
+            roots: roots.to_vec(),
+        };
+        let raw = serde_json::to_string_pretty(&persisted)
+            .map_err(|e| format!("Failed to serialize models config: {}", e))?;
+        fs::write(&self.config_path, raw)
+            .map_err(|e| format!("Failed to write models config: {}", e))
+    }
+}
+
+fn to_absolute(path: &Path) -> Result<PathBuf, String> {
+    if path.is_absolute() {
+        return Ok(path.to_path_buf());
+    }
+    std::env::current_dir()
+        .map(|cwd| cwd.join(path))
+        .map_err(|e| format!("Failed to resolve current directory: {}", e))
+}
+
+/// Collapses `.` and `..` components purely syntactically (no filesystem
+/// access, so it works for paths that don't exist yet). Mirrors what
+/// `canonicalize` does for the path shape without requiring the path, or
+/// its non-symlink ancestors, to actually exist.
+fn normalize_lexically(path: &Path) -> PathBuf {
+    let mut result = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::ParentDir => match result.components().next_back() {
+                Some(Component::Normal(_)) => {
+                    result.pop();
+                }
+                _ => result.push(component),
+            },
+            Component::CurDir => {}
+            other => result.push(other),
+        }
+    }
+    result
+}
+
+#[tauri::command]
+pub fn get_models_dir(config: tauri::State<ModelsConfig>) -> Vec<String> {
+    config
+        .roots()
+        .into_iter()
+        .map(|p| p.display().to_string())
+        .collect()
+}
+
+#[tauri::command]
+pub fn set_models_dir(dir: String, config: tauri::State<ModelsConfig>) -> Result<(), String> {
+    let path = PathBuf::from(dir);
+    fs::create_dir_all(&path).map_err(|e| format!("Failed to create models directory: {}", e))?;
+    config.add_root(path)
+}
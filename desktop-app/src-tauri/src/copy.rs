@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use tauri::Window;
+
+use crate::config::ModelsConfig;
+
+/// Read/write in 1 MiB chunks so a progress event is emitted, and the cancel
+/// flag checked, after every MiB instead of once at the end. The copy loop
+/// itself runs synchronously inside the command's async task.
+const CHUNK_SIZE: usize = 1024 * 1024;
+
+/// Payload for the `model-copy-progress` event emitted after every chunk.
+/// Shared with `download`, which emits the same event so the frontend has a
+/// single progress listener for both local copies and remote downloads.
+#[derive(Clone, Serialize)]
+pub(crate) struct CopyProgress {
+    pub(crate) cancel_token: String,
+    pub(crate) bytes_copied: u64,
+    pub(crate) total_bytes: u64,
+}
+
+/// Result returned once a copy finishes successfully.
+#[derive(Clone, Serialize)]
+pub struct CopyResult {
+    pub destination: String,
+    pub sha256: String,
+}
+
+/// Tracks in-flight transfers (copies and downloads) by their caller-supplied
+/// `cancel_token` so `cancel_model_copy` can flip the right flag.
+#[derive(Default)]
+pub struct CopyCancellationRegistry(Mutex<HashMap<String, Arc<AtomicBool>>>);
+
+impl CopyCancellationRegistry {
+    pub(crate) fn register(&self, cancel_token: &str) -> Arc<AtomicBool> {
+        let flag = Arc::new(AtomicBool::new(false));
+        self.0
+            .lock()
+            .unwrap()
+            .insert(cancel_token.to_string(), flag.clone());
+        flag
+    }
+
+    pub(crate) fn unregister(&self, cancel_token: &str) {
+        self.0.lock().unwrap().remove(cancel_token);
+    }
+}
+
+#[tauri::command]
+pub fn cancel_model_copy(cancel_token: String, registry: tauri::State<CopyCancellationRegistry>) {
+    if let Some(flag) = registry.0.lock().unwrap().get(&cancel_token) {
+        flag.store(true, Ordering::SeqCst);
+    }
+}
+
+#[tauri::command]
+pub async fn copy_model_to_folder(
+    window: Window,
+    registry: tauri::State<'_, CopyCancellationRegistry>,
+    config: tauri::State<'_, ModelsConfig>,
+    source_path: String,
+    cancel_token: String,
+) -> Result<CopyResult, String> {
+    let source = PathBuf::from(&source_path);
+
+    if !source.exists() {
+        return Err("Source file does not exist".to_string());
+    }
+
+    if source.extension().and_then(|s| s.to_str()) != Some("gguf") {
+        return Err("Only .gguf files are supported".to_string());
+    }
+
+    let models_dir = config.primary_root();
+    if !models_dir.exists() {
+        fs::create_dir_all(&models_dir)
+            .map_err(|e| format!("Failed to create models directory: {}", e))?;
+    }
+
+    let file_name = source.file_name().ok_or("Invalid file name")?;
+    let destination = config.resolve_within_roots(&models_dir.join(file_name))?;
+
+    if destination.exists() {
+        return Err("A model with this name already exists in the models folder".to_string());
+    }
+
+    let cancel_flag = registry.register(&cancel_token);
+
+    let result = run_copy(&window, &source, &destination, &cancel_token, &cancel_flag);
+
+    registry.unregister(&cancel_token);
+
+    if result.is_err() {
+        let _ = fs::remove_file(&destination);
+    }
+
+    result.map(|sha256| CopyResult {
+        destination: format!("models/{}", file_name.to_string_lossy()),
+        sha256,
+    })
+}
+
+fn run_copy(
+    window: &Window,
+    source: &PathBuf,
+    destination: &PathBuf,
+    cancel_token: &str,
+    cancel_flag: &Arc<AtomicBool>,
+) -> Result<String, String> {
+    let source_file =
+        File::open(source).map_err(|e| format!("Failed to open source file: {}", e))?;
+    let total_bytes = source_file
+        .metadata()
+        .map_err(|e| format!("Failed to read source metadata: {}", e))?
+        .len();
+
+    let mut reader = BufReader::new(source_file);
+    let dest_file = File::create(destination)
+        .map_err(|e| format!("Failed to create destination file: {}", e))?;
+    let mut writer = BufWriter::new(dest_file);
+
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0u8; CHUNK_SIZE];
+    let mut bytes_copied: u64 = 0;
+
+    loop {
+        if cancel_flag.load(Ordering::SeqCst) {
+            return Err("Copy cancelled".to_string());
+        }
+
+        let n = reader
+            .read(&mut buffer)
+            .map_err(|e| format!("Failed to read source file: {}", e))?;
+        if n == 0 {
+            break;
+        }
+
+        writer
+            .write_all(&buffer[..n])
+            .map_err(|e| format!("Failed to write destination file: {}", e))?;
+        hasher.update(&buffer[..n]);
+        bytes_copied += n as u64;
+
+        let _ = window.emit(
+            "model-copy-progress",
+            CopyProgress {
+                cancel_token: cancel_token.to_string(),
+                bytes_copied,
+                total_bytes,
+            },
+        );
+    }
+
+    writer
+        .flush()
+        .map_err(|e| format!("Failed to flush destination file: {}", e))?;
+
+    Ok(format!("{:x}", hasher.finalize()))
+}
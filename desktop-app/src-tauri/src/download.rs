@@ -0,0 +1,187 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{Seek, SeekFrom, Write};
+use std::path::{Component, PathBuf};
+use std::sync::atomic::Ordering;
+
+use tauri::Window;
+
+use crate::config::ModelsConfig;
+use crate::copy::{CopyCancellationRegistry, CopyProgress, CopyResult};
+
+/// Partial downloads are written next to the final destination with this
+/// suffix, so an interrupted transfer can be resumed with a Range request
+/// instead of starting over.
+const PARTIAL_SUFFIX: &str = ".part";
+
+#[tauri::command]
+pub async fn download_model(
+    window: Window,
+    registry: tauri::State<'_, CopyCancellationRegistry>,
+    config: tauri::State<'_, ModelsConfig>,
+    url: String,
+    filename: String,
+    cancel_token: String,
+) -> Result<CopyResult, String> {
+    // `filename` is caller-controlled, so it must name a single file in the
+    // destination directory rather than a path that could climb out of it
+    // (e.g. `../../../../tmp/evil.gguf`).
+    let filename_path = PathBuf::from(&filename);
+    if !matches!(
+        filename_path.components().collect::<Vec<_>>().as_slice(),
+        [Component::Normal(_)]
+    ) {
+        return Err("filename must be a single file name, not a path".to_string());
+    }
+
+    if filename_path.extension().and_then(|e| e.to_str()) != Some("gguf") {
+        return Err("Only .gguf files are supported".to_string());
+    }
+
+    let models_dir = config.primary_root();
+    if !models_dir.exists() {
+        fs::create_dir_all(&models_dir)
+            .map_err(|e| format!("Failed to create models directory: {}", e))?;
+    }
+
+    let destination = config.resolve_within_roots(&models_dir.join(&filename))?;
+    if destination.exists() {
+        return Err("A model with this name already exists in the models folder".to_string());
+    }
+
+    let partial_path =
+        config.resolve_within_roots(&models_dir.join(format!("{}{}", filename, PARTIAL_SUFFIX)))?;
+
+    let cancel_flag = registry.register(&cancel_token);
+    let result = run_download(
+        &window,
+        &url,
+        &partial_path,
+        &destination,
+        &cancel_token,
+        &cancel_flag,
+    )
+    .await;
+    registry.unregister(&cancel_token);
+
+    if result.is_err() && !cancel_flag.load(Ordering::SeqCst) {
+        // Any failure other than a user-requested cancel removes the partial
+        // file, so a transfer cut off by a network error starts over on the
+        // next call; only a cancelled transfer (or one interrupted by the app
+        // exiting) leaves a partial file behind to resume.
+        let _ = fs::remove_file(&partial_path);
+    }
+
+    result.map(|sha256| CopyResult {
+        destination: format!("models/{}", filename),
+        sha256,
+    })
+}
+
+async fn run_download(
+    window: &Window,
+    url: &str,
+    partial_path: &PathBuf,
+    destination: &PathBuf,
+    cancel_token: &str,
+    cancel_flag: &std::sync::Arc<std::sync::atomic::AtomicBool>,
+) -> Result<String, String> {
+    let client = reqwest::Client::new();
+
+    let resume_from = partial_path.metadata().map(|m| m.len()).unwrap_or(0);
+
+    let mut request = client.get(url);
+    if resume_from > 0 {
+        request = request.header("Range", format!("bytes={}-", resume_from));
+    }
+
+    let response = request
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach {}: {}", url, e))?;
+
+    if !response.status().is_success() && response.status().as_u16() != 206 {
+        return Err(format!("Download failed with status {}", response.status()));
+    }
+
+    let resumed = response.status().as_u16() == 206;
+    let content_length = response.content_length().unwrap_or(0);
+    let total_bytes = if resumed {
+        resume_from + content_length
+    } else {
+        content_length
+    };
+
+    let mut file = if resumed {
+        let mut f = OpenOptions::new()
+            .append(true)
+            .open(partial_path)
+            .map_err(|e| format!("Failed to resume partial download: {}", e))?;
+        f.seek(SeekFrom::End(0))
+            .map_err(|e| format!("Failed to seek partial download: {}", e))?;
+        f
+    } else {
+        File::create(partial_path)
+            .map_err(|e| format!("Failed to create partial download file: {}", e))?
+    };
+
+    let mut bytes_copied = if resumed { resume_from } else { 0 };
+    let mut hasher = sha2::Sha256::new();
+    if resumed {
+        // Re-hash the bytes already on disk so the final checksum still
+        // covers the whole file, not just this resumed tail.
+        let mut existing = File::open(partial_path)
+            .map_err(|e| format!("Failed to reopen partial download: {}", e))?;
+        std::io::copy(&mut existing, &mut HashSink(&mut hasher))
+            .map_err(|e| format!("Failed to hash existing partial download: {}", e))?;
+    }
+
+    use futures_util::StreamExt as _;
+    let mut stream = response.bytes_stream();
+
+    while let Some(chunk) = stream.next().await {
+        if cancel_flag.load(Ordering::SeqCst) {
+            return Err("Download cancelled".to_string());
+        }
+
+        let chunk = chunk.map_err(|e| format!("Failed reading response body: {}", e))?;
+        file.write_all(&chunk)
+            .map_err(|e| format!("Failed to write downloaded bytes: {}", e))?;
+
+        use sha2::Digest;
+        hasher.update(&chunk);
+        bytes_copied += chunk.len() as u64;
+
+        let _ = window.emit(
+            "model-copy-progress",
+            CopyProgress {
+                cancel_token: cancel_token.to_string(),
+                bytes_copied,
+                total_bytes: total_bytes.max(bytes_copied),
+            },
+        );
+    }
+
+    file.flush()
+        .map_err(|e| format!("Failed to flush downloaded file: {}", e))?;
+    drop(file);
+
+    fs::rename(partial_path, destination)
+        .map_err(|e| format!("Failed to finalize downloaded file: {}", e))?;
+
+    use sha2::Digest;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+struct HashSink<'a>(&'a mut sha2::Sha256);
+
+impl<'a> Write for HashSink<'a> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        use sha2::Digest;
+        self.0.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
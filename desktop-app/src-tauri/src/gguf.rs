@@ -0,0 +1,305 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::config::ModelsConfig;
+
+const GGUF_MAGIC: &[u8; 4] = b"GGUF";
+
+/// Sane upper bounds for length fields read straight out of an untrusted
+/// header, so a malformed or hostile `.gguf` can't force a multi-gigabyte
+/// allocation (and an abort) before we've even validated the value it
+/// names. Real GGUF metadata strings are names/identifiers, arrays are
+/// things like tokenizer vocabularies, and files have at most a few
+/// thousand KV pairs — these limits are generous relative to that.
+const MAX_STRING_LEN: u64 = 16 * 1024 * 1024;
+const MAX_ARRAY_LEN: u64 = 1_000_000;
+const MAX_METADATA_KV_COUNT: u64 = 100_000;
+
+/// Arrays can nest (an array of arrays), and each nested level costs only a
+/// few header bytes, so without a depth cap a tiny crafted file can recurse
+/// deep enough to blow the stack. Real GGUF metadata never nests arrays.
+const MAX_ARRAY_DEPTH: u32 = 8;
+
+/// Subset of a GGUF file's key-value header that the UI cares about:
+/// enough to show architecture/quant/context size without loading the
+/// model into the inference server.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct GgufMetadata {
+    pub architecture: Option<String>,
+    pub name: Option<String>,
+    pub quantization: Option<String>,
+    pub context_length: Option<u64>,
+}
+
+#[derive(Debug, Clone)]
+enum GgufValue {
+    UInt8(u8),
+    Int8(i8),
+    UInt16(u16),
+    Int16(i16),
+    UInt32(u32),
+    Int32(i32),
+    Float32(f32),
+    Bool(bool),
+    String(String),
+    Array(Vec<GgufValue>),
+    UInt64(u64),
+    Int64(i64),
+    Float64(f64),
+}
+
+impl GgufValue {
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            GgufValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    fn as_u64(&self) -> Option<u64> {
+        match self {
+            GgufValue::UInt8(v) => Some(*v as u64),
+            GgufValue::UInt16(v) => Some(*v as u64),
+            GgufValue::UInt32(v) => Some(*v as u64),
+            GgufValue::UInt64(v) => Some(*v),
+            GgufValue::Int8(v) if *v >= 0 => Some(*v as u64),
+            GgufValue::Int16(v) if *v >= 0 => Some(*v as u64),
+            GgufValue::Int32(v) if *v >= 0 => Some(*v as u64),
+            GgufValue::Int64(v) if *v >= 0 => Some(*v as u64),
+            _ => None,
+        }
+    }
+}
+
+/// Parse the GGUF key-value header of `path` and pull out the fields the UI
+/// shows in the model list. Tensor data itself is never read. `path` must
+/// fall within a registered models root — this is a read primitive exposed
+/// directly to the frontend, so it's gated through the same allowlist as
+/// the write commands instead of opening whatever path it's given.
+#[tauri::command]
+pub fn read_gguf_metadata(
+    path: String,
+    config: tauri::State<ModelsConfig>,
+) -> Result<GgufMetadata, String> {
+    let resolved = config.resolve_within_roots(Path::new(&path))?;
+    parse_gguf_metadata(&resolved)
+}
+
+pub fn parse_gguf_metadata(path: &Path) -> Result<GgufMetadata, String> {
+    let file = File::open(path).map_err(|e| format!("Failed to open {:?}: {}", path, e))?;
+    let mut reader = BufReader::new(file);
+
+    let mut magic = [0u8; 4];
+    reader
+        .read_exact(&mut magic)
+        .map_err(|e| format!("Failed to read GGUF magic from {:?}: {}", path, e))?;
+    if &magic != GGUF_MAGIC {
+        return Err(format!("{:?} is not a GGUF file (bad magic)", path));
+    }
+
+    let _version = read_u32(&mut reader)?;
+    let _tensor_count = read_u64(&mut reader)?;
+    let metadata_kv_count = read_u64(&mut reader)?;
+    if metadata_kv_count > MAX_METADATA_KV_COUNT {
+        return Err(format!(
+            "{:?} reports {} metadata entries, more than the {} we'll parse",
+            path, metadata_kv_count, MAX_METADATA_KV_COUNT
+        ));
+    }
+
+    let mut kv = HashMap::with_capacity(metadata_kv_count as usize);
+    for _ in 0..metadata_kv_count {
+        let key = read_string(&mut reader)?;
+        let value_type = read_u32(&mut reader)?;
+        let value = read_value(&mut reader, value_type, 0)?;
+        kv.insert(key, value);
+    }
+
+    let architecture = kv
+        .get("general.architecture")
+        .and_then(GgufValue::as_str)
+        .map(String::from);
+
+    let name = kv
+        .get("general.name")
+        .and_then(GgufValue::as_str)
+        .map(String::from);
+
+    let quantization = kv
+        .get("general.file_type")
+        .and_then(GgufValue::as_u64)
+        .map(|ft| ggml_file_type_name(ft).to_string())
+        .or_else(|| {
+            kv.get("general.quantization_version")
+                .and_then(GgufValue::as_u64)
+                .map(|v| format!("quantization v{}", v))
+        });
+
+    let context_length = architecture.as_deref().and_then(|arch| {
+        kv.get(&format!("{}.context_length", arch))
+            .and_then(GgufValue::as_u64)
+    });
+
+    Ok(GgufMetadata {
+        architecture,
+        name,
+        quantization,
+        context_length,
+    })
+}
+
+/// Maps `general.file_type` (llama.cpp's `llama_ftype` enum) to the quant name
+/// llama.cpp users recognize (e.g. `Q4_K_M`). Unknown values still surface
+/// as a readable placeholder instead of silently disappearing.
+fn ggml_file_type_name(file_type: u64) -> &'static str {
+    match file_type {
+        0 => "F32",
+        1 => "F16",
+        2 => "Q4_0",
+        3 => "Q4_1",
+        7 => "Q8_0",
+        8 => "Q5_0",
+        9 => "Q5_1",
+        10 => "Q2_K",
+        11 => "Q3_K_S",
+        12 => "Q3_K_M",
+        13 => "Q3_K_L",
+        14 => "Q4_K_S",
+        15 => "Q4_K_M",
+        16 => "Q5_K_S",
+        17 => "Q5_K_M",
+        18 => "Q6_K",
+        19 => "IQ2_XXS",
+        20 => "IQ2_XS",
+        21 => "Q2_K_S",
+        25 => "IQ4_NL",
+        _ => "unknown",
+    }
+}
+
+fn read_u8<R: Read>(r: &mut R) -> Result<u8, String> {
+    let mut buf = [0u8; 1];
+    r.read_exact(&mut buf)
+        .map_err(|e| format!("Failed to read u8: {}", e))?;
+    Ok(buf[0])
+}
+
+fn read_u16<R: Read>(r: &mut R) -> Result<u16, String> {
+    let mut buf = [0u8; 2];
+    r.read_exact(&mut buf)
+        .map_err(|e| format!("Failed to read u16: {}", e))?;
+    Ok(u16::from_le_bytes(buf))
+}
+
+fn read_u32<R: Read>(r: &mut R) -> Result<u32, String> {
+    let mut buf = [0u8; 4];
+    r.read_exact(&mut buf)
+        .map_err(|e| format!("Failed to read u32: {}", e))?;
+    Ok(u32::from_le_bytes(buf))
+}
+
+fn read_u64<R: Read>(r: &mut R) -> Result<u64, String> {
+    let mut buf = [0u8; 8];
+    r.read_exact(&mut buf)
+        .map_err(|e| format!("Failed to read u64: {}", e))?;
+    Ok(u64::from_le_bytes(buf))
+}
+
+fn read_i8<R: Read>(r: &mut R) -> Result<i8, String> {
+    let mut buf = [0u8; 1];
+    r.read_exact(&mut buf)
+        .map_err(|e| format!("Failed to read i8: {}", e))?;
+    Ok(buf[0] as i8)
+}
+
+fn read_i16<R: Read>(r: &mut R) -> Result<i16, String> {
+    let mut buf = [0u8; 2];
+    r.read_exact(&mut buf)
+        .map_err(|e| format!("Failed to read i16: {}", e))?;
+    Ok(i16::from_le_bytes(buf))
+}
+
+fn read_i32<R: Read>(r: &mut R) -> Result<i32, String> {
+    let mut buf = [0u8; 4];
+    r.read_exact(&mut buf)
+        .map_err(|e| format!("Failed to read i32: {}", e))?;
+    Ok(i32::from_le_bytes(buf))
+}
+
+fn read_i64<R: Read>(r: &mut R) -> Result<i64, String> {
+    let mut buf = [0u8; 8];
+    r.read_exact(&mut buf)
+        .map_err(|e| format!("Failed to read i64: {}", e))?;
+    Ok(i64::from_le_bytes(buf))
+}
+
+fn read_f32<R: Read>(r: &mut R) -> Result<f32, String> {
+    let mut buf = [0u8; 4];
+    r.read_exact(&mut buf)
+        .map_err(|e| format!("Failed to read f32: {}", e))?;
+    Ok(f32::from_le_bytes(buf))
+}
+
+fn read_f64<R: Read>(r: &mut R) -> Result<f64, String> {
+    let mut buf = [0u8; 8];
+    r.read_exact(&mut buf)
+        .map_err(|e| format!("Failed to read f64: {}", e))?;
+    Ok(f64::from_le_bytes(buf))
+}
+
+fn read_string<R: Read>(r: &mut R) -> Result<String, String> {
+    let len = read_u64(r)?;
+    if len > MAX_STRING_LEN {
+        return Err(format!(
+            "GGUF string length {} exceeds the {} byte limit",
+            len, MAX_STRING_LEN
+        ));
+    }
+    let mut buf = vec![0u8; len as usize];
+    r.read_exact(&mut buf)
+        .map_err(|e| format!("Failed to read string body: {}", e))?;
+    String::from_utf8(buf).map_err(|e| format!("Invalid UTF-8 in GGUF string: {}", e))
+}
+
+fn read_value<R: Read>(r: &mut R, value_type: u32, depth: u32) -> Result<GgufValue, String> {
+    match value_type {
+        0 => Ok(GgufValue::UInt8(read_u8(r)?)),
+        1 => Ok(GgufValue::Int8(read_i8(r)?)),
+        2 => Ok(GgufValue::UInt16(read_u16(r)?)),
+        3 => Ok(GgufValue::Int16(read_i16(r)?)),
+        4 => Ok(GgufValue::UInt32(read_u32(r)?)),
+        5 => Ok(GgufValue::Int32(read_i32(r)?)),
+        6 => Ok(GgufValue::Float32(read_f32(r)?)),
+        7 => Ok(GgufValue::Bool(read_u8(r)? != 0)),
+        8 => Ok(GgufValue::String(read_string(r)?)),
+        9 => {
+            if depth >= MAX_ARRAY_DEPTH {
+                return Err(format!(
+                    "GGUF array nesting exceeds the {} level limit",
+                    MAX_ARRAY_DEPTH
+                ));
+            }
+            let array_type = read_u32(r)?;
+            let array_len = read_u64(r)?;
+            if array_len > MAX_ARRAY_LEN {
+                return Err(format!(
+                    "GGUF array length {} exceeds the {} element limit",
+                    array_len, MAX_ARRAY_LEN
+                ));
+            }
+            let mut values = Vec::with_capacity(array_len as usize);
+            for _ in 0..array_len {
+                values.push(read_value(r, array_type, depth + 1)?);
+            }
+            Ok(GgufValue::Array(values))
+        }
+        10 => Ok(GgufValue::UInt64(read_u64(r)?)),
+        11 => Ok(GgufValue::Int64(read_i64(r)?)),
+        12 => Ok(GgufValue::Float64(read_f64(r)?)),
+        other => Err(format!("Unknown GGUF value type: {}", other)),
+    }
+}
@@ -0,0 +1,150 @@
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use serde::Serialize;
+use walkdir::WalkDir;
+
+use crate::config::ModelsConfig;
+use crate::gguf;
+
+/// Maximum directory depth `scan_directory` will descend into by default.
+/// Keeps a stray symlink loop or a deeply nested cache dir from turning a
+/// scan into an unbounded walk.
+const DEFAULT_MAX_DEPTH: usize = 8;
+
+/// A single `.gguf` file discovered under a models root, with enough
+/// metadata for the UI to sort and display it without re-stating the file
+/// itself.
+#[derive(Debug, Clone, Serialize)]
+pub struct ModelEntry {
+    pub name: String,
+    pub relative_path: String,
+    pub size_bytes: u64,
+    pub modified: Option<u64>,
+    pub created: Option<u64>,
+    pub is_symlink: bool,
+    pub architecture: Option<String>,
+    pub quantization: Option<String>,
+    pub context_length: Option<u64>,
+}
+
+#[tauri::command]
+pub fn scan_models_folder(config: tauri::State<ModelsConfig>) -> Result<Vec<ModelEntry>, String> {
+    scan_directory(&config.roots(), DEFAULT_MAX_DEPTH, false)
+}
+
+/// Recursively walk `roots`, returning a `ModelEntry` for every `.gguf` file
+/// found at any depth, sorted by relative path for stable output.
+/// `max_depth` is passed straight through to `WalkDir::max_depth` (1 == only
+/// the root's direct children, matching the old `read_dir` behavior). When
+/// `follow_links` is set, symlinked directories are traversed too.
+pub fn scan_directory(
+    roots: &[PathBuf],
+    max_depth: usize,
+    follow_links: bool,
+) -> Result<Vec<ModelEntry>, String> {
+    let mut model_files = Vec::new();
+
+    for models_dir in roots {
+        if !models_dir.exists() {
+            eprintln!("Models folder not found at: {:?}", models_dir);
+            continue;
+        }
+
+        eprintln!("Scanning models folder at: {:?}", models_dir);
+
+        let walker = WalkDir::new(models_dir)
+            .max_depth(max_depth)
+            .follow_links(follow_links);
+
+        for entry in walker {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(e) => {
+                    eprintln!("Error walking directory: {}", e);
+                    continue;
+                }
+            };
+
+            let file_type = entry.file_type();
+            if !file_type.is_file() {
+                // With `follow_links(false)`, WalkDir reports a symlink as
+                // neither a file nor a directory, so a symlinked `.gguf`
+                // would otherwise be skipped outright. Resolve it to see
+                // what it actually points at before deciding.
+                if !file_type.is_symlink() || !entry.path().is_file() {
+                    continue;
+                }
+            }
+
+            match build_model_entry(entry.path(), models_dir) {
+                Ok(Some(model_entry)) => {
+                    eprintln!("Found model: models/{}", model_entry.relative_path);
+                    model_files.push(model_entry);
+                }
+                Ok(None) => {}
+                Err(e) => eprintln!("Error reading {:?}: {}", entry.path(), e),
+            }
+        }
+    }
+
+    model_files.sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
+    eprintln!("Total models found: {}", model_files.len());
+    Ok(model_files)
+}
+
+/// Builds a `ModelEntry` for a single `.gguf` file relative to `root`.
+/// Returns `Ok(None)` for paths that aren't a `.gguf` file (not an error,
+/// just nothing to report) so callers like the file watcher can call this
+/// directly on whatever path a filesystem event names.
+pub fn build_model_entry(path: &Path, root: &Path) -> Result<Option<ModelEntry>, String> {
+    if path.extension().and_then(|e| e.to_str()) != Some("gguf") {
+        return Ok(None);
+    }
+
+    let relative = path.strip_prefix(root).unwrap_or(path);
+    let Some(relative_str) = relative.to_str() else {
+        return Ok(None);
+    };
+    let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+        return Ok(None);
+    };
+
+    let link_metadata = path
+        .symlink_metadata()
+        .map_err(|e| format!("Failed to read metadata: {}", e))?;
+    let is_symlink = link_metadata.is_symlink();
+
+    // A symlink's own metadata describes the link (e.g. a few dozen bytes),
+    // not the file it points at, so size/timestamps need the resolved
+    // target's metadata instead.
+    let metadata = if is_symlink {
+        path.metadata()
+            .map_err(|e| format!("Failed to read metadata: {}", e))?
+    } else {
+        link_metadata
+    };
+    if !metadata.is_file() {
+        return Ok(None);
+    }
+
+    // Best-effort: a model with a malformed or truncated header should
+    // still show up in the list, just without the extra detail.
+    let gguf_metadata = gguf::parse_gguf_metadata(path).unwrap_or_default();
+
+    Ok(Some(ModelEntry {
+        name: name.to_string(),
+        relative_path: relative_str.to_string(),
+        size_bytes: metadata.len(),
+        modified: metadata.modified().ok().and_then(to_unix_timestamp),
+        created: metadata.created().ok().and_then(to_unix_timestamp),
+        is_symlink,
+        architecture: gguf_metadata.architecture,
+        quantization: gguf_metadata.quantization,
+        context_length: gguf_metadata.context_length,
+    }))
+}
+
+fn to_unix_timestamp(time: std::time::SystemTime) -> Option<u64> {
+    time.duration_since(UNIX_EPOCH).ok().map(|d| d.as_secs())
+}
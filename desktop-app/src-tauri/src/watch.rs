@@ -0,0 +1,211 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{channel, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+use notify::event::{ModifyKind, RenameMode};
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::Serialize;
+use tauri::Window;
+
+use crate::config::ModelsConfig;
+use crate::models::{self, ModelEntry};
+
+/// Raw filesystem events are coalesced over this window before being turned
+/// into `model-*` events, so a multi-gigabyte copy-in-progress doesn't fire
+/// dozens of add/remove pairs as the OS reports partial writes.
+const DEBOUNCE: Duration = Duration::from_millis(500);
+
+#[derive(Clone)]
+enum PendingChange {
+    Added,
+    Removed,
+    RenamedFrom(PathBuf),
+}
+
+#[derive(Clone, Serialize)]
+struct ModelRenamed {
+    from: String,
+    model: ModelEntry,
+}
+
+/// Spawns a background thread watching every configured models root and
+/// emits `model-added` / `model-removed` / `model-renamed` events as `.gguf`
+/// files come and go. `model-added` carries the new `ModelEntry` and
+/// `model-renamed` the entry plus its old relative path; `model-removed`
+/// carries only the removed file's relative path, not a `ModelEntry`.
+#[tauri::command]
+pub fn watch_models_folder(
+    window: Window,
+    config: tauri::State<ModelsConfig>,
+) -> Result<(), String> {
+    let roots = config.roots();
+    let (tx, rx) = channel();
+
+    let mut watcher: RecommendedWatcher = notify::recommended_watcher(tx)
+        .map_err(|e| format!("Failed to create filesystem watcher: {}", e))?;
+
+    for root in &roots {
+        if let Err(e) = watcher.watch(root, RecursiveMode::Recursive) {
+            eprintln!("Failed to watch {:?}: {}", root, e);
+        }
+    }
+
+    // Roots can be configured relatively (e.g. the dev default
+    // `../../models`), but some watcher backends (notably macOS FSEvents)
+    // always deliver absolute event paths. Canonicalize once up front so
+    // `root_for` compares like with like instead of silently matching
+    // nothing.
+    let canonical_roots: Vec<PathBuf> = roots
+        .iter()
+        .map(|root| root.canonicalize().unwrap_or_else(|_| root.clone()))
+        .collect();
+
+    thread::spawn(move || {
+        // Keep the watcher alive for the lifetime of the thread; dropping it
+        // would stop delivering events.
+        let _watcher = watcher;
+        run_debounced_loop(&window, &canonical_roots, rx);
+    });
+
+    Ok(())
+}
+
+fn run_debounced_loop(
+    window: &Window,
+    roots: &[PathBuf],
+    rx: std::sync::mpsc::Receiver<notify::Result<notify::Event>>,
+) {
+    let mut pending: HashMap<PathBuf, PendingChange> = HashMap::new();
+
+    loop {
+        match rx.recv_timeout(DEBOUNCE) {
+            Ok(Ok(event)) => record_event(&mut pending, event),
+            Ok(Err(e)) => eprintln!("Filesystem watch error: {}", e),
+            Err(RecvTimeoutError::Timeout) => {
+                if !pending.is_empty() {
+                    flush_pending(window, roots, std::mem::take(&mut pending));
+                }
+            }
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+    }
+}
+
+fn record_event(pending: &mut HashMap<PathBuf, PendingChange>, event: notify::Event) {
+    match event.kind {
+        EventKind::Create(_) => {
+            for path in event.paths {
+                if is_gguf(&path) {
+                    pending.insert(path, PendingChange::Added);
+                }
+            }
+        }
+        EventKind::Remove(_) => {
+            for path in event.paths {
+                if is_gguf(&path) {
+                    pending.insert(path, PendingChange::Removed);
+                }
+            }
+        }
+        // When the platform reports both halves of a rename together we get
+        // `[from, to]` in one event; fall back to treating each half as a
+        // plain remove/add when only one side is delivered.
+        EventKind::Modify(ModifyKind::Name(RenameMode::Both)) if event.paths.len() == 2 => {
+            let from = event.paths[0].clone();
+            let to = event.paths[1].clone();
+            if is_gguf(&to) {
+                pending.insert(to, PendingChange::RenamedFrom(from));
+            } else if is_gguf(&from) {
+                pending.insert(from, PendingChange::Removed);
+            }
+        }
+        EventKind::Modify(ModifyKind::Name(RenameMode::From)) => {
+            for path in event.paths {
+                if is_gguf(&path) {
+                    pending.insert(path, PendingChange::Removed);
+                }
+            }
+        }
+        EventKind::Modify(ModifyKind::Name(RenameMode::To)) => {
+            for path in event.paths {
+                if is_gguf(&path) {
+                    pending.insert(path, PendingChange::Added);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn is_gguf(path: &PathBuf) -> bool {
+    path.extension().and_then(|e| e.to_str()) == Some("gguf")
+}
+
+/// `roots` are expected to already be canonicalized (see
+/// `watch_models_folder`) and `path` to have gone through
+/// `canonicalize_event_path`; this is a plain prefix match.
+fn root_for(path: &Path, roots: &[PathBuf]) -> Option<PathBuf> {
+    roots.iter().find(|root| path.starts_with(root)).cloned()
+}
+
+/// `inotify` (and other backends) can deliver event paths in whatever form
+/// the root was registered in, e.g. the relative dev default
+/// `../../models/foo.gguf`, while `canonical_roots` are always absolute. Event
+/// paths are canonicalized once here so everything downstream — matching a
+/// root, building a `ModelEntry`, computing the relative path — compares
+/// like with like against the canonical root, the same way `scan_directory`'s
+/// `WalkDir::new(models_dir)` walk already does.
+fn canonicalize_event_path(path: &Path) -> PathBuf {
+    if let Ok(canonical) = path.canonicalize() {
+        return canonical;
+    }
+    // A removed (or renamed-away) file can't be canonicalized itself, but
+    // its parent directory usually still exists.
+    match (path.parent().and_then(|p| p.canonicalize().ok()), path.file_name()) {
+        (Some(parent), Some(name)) => parent.join(name),
+        _ => path.to_path_buf(),
+    }
+}
+
+fn flush_pending(window: &Window, roots: &[PathBuf], pending: HashMap<PathBuf, PendingChange>) {
+    for (path, change) in pending {
+        let path = canonicalize_event_path(&path);
+        let Some(root) = root_for(&path, roots) else {
+            continue;
+        };
+
+        match change {
+            PendingChange::Added => {
+                if let Ok(Some(entry)) = models::build_model_entry(&path, &root) {
+                    let _ = window.emit("model-added", entry);
+                }
+            }
+            PendingChange::Removed => {
+                let relative = relative_string(&path, &root);
+                let _ = window.emit("model-removed", relative);
+            }
+            PendingChange::RenamedFrom(from) => {
+                if let Ok(Some(entry)) = models::build_model_entry(&path, &root) {
+                    let from = canonicalize_event_path(&from);
+                    let from_root = root_for(&from, roots).unwrap_or_else(|| root.clone());
+                    let _ = window.emit(
+                        "model-renamed",
+                        ModelRenamed {
+                            from: relative_string(&from, &from_root),
+                            model: entry,
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn relative_string(path: &PathBuf, root: &PathBuf) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .to_string()
+}